@@ -16,40 +16,633 @@
 
 use std::{marker::PhantomData, sync::Arc};
 
+use codec::{Decode, Encode};
+use sc_client_api::backend::AuxStore;
+use sc_utils::notification::{NotificationSender, NotificationStream, TracingKeyStr};
 use sp_api::ProvideRuntimeApi;
+use sp_application_crypto::RuntimeAppPublic;
 use sp_block_builder::BlockBuilder as BlockBuilderApi;
-use sp_blockchain::Result as ClientResult;
+use sp_blockchain::{Error as ClientError, Result as ClientResult};
 use sp_consensus::{
 	error::Error as ConsensusError,
 	import_queue::{BasicQueue, CacheKeyId, Verifier as VerifierT},
 	BlockImport, BlockImportParams, BlockOrigin, ForkChoiceStrategy,
 };
+use sp_consensus_aura::AuraApi;
+use sp_consensus_slots::Slot;
+use sp_finality_grandpa::{AuthorityList as GrandpaAuthorityList, GRANDPA_ENGINE_ID};
 use sp_inherents::{CreateInherentDataProviders, InherentDataProvider};
 use sp_runtime::{
-	generic::BlockId,
-	traits::{Block as BlockT, Header as HeaderT},
+	generic::{BlockId, DigestItem, OpaqueDigestItemId},
+	traits::{Block as BlockT, Header as HeaderT, NumberFor},
 	Justifications,
 };
 
-/// A verifier that just checks the inherents.
-struct Verifier<Client, Block, CIDP> {
+/// Prometheus metrics for the [`Verifier`].
+mod metrics {
+	use substrate_prometheus_endpoint::{
+		register, CounterVec, Histogram, HistogramOpts, Opts, PrometheusError, Registry, U64,
+	};
+
+	/// Label used for inherent-check errors that no registered inherent data provider could
+	/// explain, as opposed to ones that were at least identified by their inherent identifier
+	/// (timestamp drift, parachain validation-data mismatch, ...).
+	pub(crate) const UNHANDLED_INHERENT_LABEL: &str = "unhandled";
+
+	#[derive(Clone)]
+	pub(crate) struct Metrics {
+		/// Time spent building inherent data and running `check_inherents`, per verified block.
+		check_inherents_time: Histogram,
+		/// Inherent-check errors, partitioned by the inherent identifier that produced them (or
+		/// [`UNHANDLED_INHERENT_LABEL`] when none could be determined).
+		inherent_errors: CounterVec<U64>,
+	}
+
+	impl Metrics {
+		pub(crate) fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+			Ok(Self {
+				check_inherents_time: register(
+					Histogram::with_opts(HistogramOpts::new(
+						"cumulus_collator_verifier_check_inherents_time",
+						"Time spent creating inherent data and checking inherents in the relay \
+						 chain verifier",
+					))?,
+					registry,
+				)?,
+				inherent_errors: register(
+					CounterVec::new(
+						Opts::new(
+							"cumulus_collator_verifier_inherent_errors",
+							"Number of inherent check errors, by the inherent identifier that \
+							 produced them",
+						),
+						&["inherent"],
+					)?,
+					registry,
+				)?,
+			})
+		}
+
+		pub(crate) fn observe_check_inherents_time(&self, seconds: f64) {
+			self.check_inherents_time.observe(seconds);
+		}
+
+		pub(crate) fn report_inherent_error(&self, inherent: &str) {
+			self.inherent_errors.with_label_values(&[inherent]).inc();
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+
+		#[test]
+		fn reported_errors_are_partitioned_by_inherent() {
+			let registry = Registry::new();
+			let metrics = Metrics::register(&registry).unwrap();
+
+			metrics.report_inherent_error("aura");
+			metrics.report_inherent_error("aura");
+			metrics.report_inherent_error(UNHANDLED_INHERENT_LABEL);
+
+			let families = registry.gather();
+			let inherent_errors = families
+				.iter()
+				.find(|f| f.get_name() == "cumulus_collator_verifier_inherent_errors")
+				.expect("inherent_errors counter is registered");
+
+			let counts: std::collections::HashMap<_, _> = inherent_errors
+				.get_metric()
+				.iter()
+				.map(|m| (m.get_label()[0].get_value().to_string(), m.get_counter().get_value()))
+				.collect();
+
+			assert_eq!(counts.get("aura"), Some(&2.0));
+			assert_eq!(counts.get(UNHANDLED_INHERENT_LABEL), Some(&1.0));
+		}
+	}
+}
+
+/// Aux-storage helpers the [`Verifier`] uses to persist state across restarts, folded into the
+/// same aux transaction as the block import that produced it.
+mod aux_schema {
+	use super::*;
+
+	fn relay_parent_key(hash: &impl Encode) -> Vec<u8> {
+		(b"cumulus_relay_chain_verifier_relay_parent", hash).encode()
+	}
+
+	/// Load the relay-parent number the block `hash` was built against, as recorded by
+	/// [`write_relay_parent`] when that block was imported.
+	pub(crate) fn load_relay_parent<Block: BlockT>(
+		backend: &dyn AuxStore,
+		hash: &Block::Hash,
+	) -> ClientResult<Option<u32>> {
+		backend
+			.get_aux(&relay_parent_key(hash))?
+			.map(|raw| u32::decode(&mut &raw[..]))
+			.transpose()
+			.map_err(|e| ClientError::Backend(format!("failed to decode relay parent number: {:?}", e)))
+	}
+
+	/// Persist the relay-parent number `hash` was built against, via `write_aux`, folded into the
+	/// same aux transaction as the block import.
+	pub(crate) fn write_relay_parent<Block: BlockT, R>(
+		hash: &Block::Hash,
+		relay_parent_number: u32,
+		write_aux: impl FnOnce(&[(&[u8], &[u8])]) -> R,
+	) -> R {
+		write_aux(&[(&relay_parent_key(hash), relay_parent_number.encode().as_slice())])
+	}
+
+	fn grandpa_authority_set_key(hash: &impl Encode) -> Vec<u8> {
+		(b"cumulus_relay_chain_verifier_grandpa_authority_set", hash).encode()
+	}
+
+	/// Load the GRANDPA authority set active immediately after importing `hash`, as recorded by
+	/// [`write_grandpa_authority_set`] when that block was imported, or `None` if `hash` hasn't
+	/// been seen yet (e.g. it is the genesis block, or its ancestry predates this check being
+	/// enabled).
+	///
+	/// Keyed per-hash, like [`load_relay_parent`], rather than tracked as a single global set:
+	/// with async backing several competing not-yet-included candidates can be verified through
+	/// the same queue concurrently, and each fork must only ever see the authority-set changes
+	/// its own ancestry actually announced.
+	pub(crate) fn load_grandpa_authority_set<Block: BlockT>(
+		backend: &dyn AuxStore,
+		hash: &Block::Hash,
+	) -> ClientResult<Option<super::GrandpaAuthoritySet<NumberFor<Block>>>> {
+		backend
+			.get_aux(&grandpa_authority_set_key(hash))?
+			.map(|raw| super::GrandpaAuthoritySet::<NumberFor<Block>>::decode(&mut &raw[..]))
+			.transpose()
+			.map_err(|e| ClientError::Backend(format!("failed to decode GRANDPA authority set: {:?}", e)))
+	}
+
+	/// Persist the GRANDPA authority set active immediately after importing `hash`, via
+	/// `write_aux`, folded into the same aux transaction as the block import that changed it.
+	pub(crate) fn write_grandpa_authority_set<Block: BlockT, R>(
+		hash: &Block::Hash,
+		authority_set: &super::GrandpaAuthoritySet<NumberFor<Block>>,
+		write_aux: impl FnOnce(&[(&[u8], &[u8])]) -> R,
+	) -> R {
+		write_aux(&[(&grandpa_authority_set_key(hash), authority_set.encode().as_slice())])
+	}
+}
+
+/// A standard GRANDPA authority-set change that has been announced but whose `delay` has not yet
+/// elapsed.
+#[derive(Clone, Encode, Decode)]
+pub struct GrandpaPendingChange<N> {
+	/// The block number from which `next_authorities` becomes the active set, i.e. the number of
+	/// the block that announced the change plus its `delay`.
+	pub effective_at: N,
+	/// The authority set that becomes active at `effective_at`.
+	pub next_authorities: GrandpaAuthorityList,
+}
+
+/// The GRANDPA authority set a justification is checked against, tracked per-fork in aux storage
+/// so that a standard scheduled change carried by a later block's digest can update it. Forced
+/// changes are not applied to it.
+#[derive(Clone, Encode, Decode)]
+pub struct GrandpaAuthoritySet<N> {
+	/// Monotonically increasing id of the current set; bumped on every authority-set change.
+	pub set_id: u64,
+	/// The authorities (and their voting weight) in the current set.
+	pub authorities: GrandpaAuthorityList,
+	/// A standard change that has been seen but is not active yet, because its `delay` hasn't
+	/// elapsed.
+	pub pending_change: Option<GrandpaPendingChange<N>>,
+}
+
+/// Verify `justifications` finalizes `(hash, number)` against `authority_set`, returning whether a
+/// matching GRANDPA justification was found and checked out.
+///
+/// A missing GRANDPA justification is not an error: not every block carries one. An invalid one
+/// is.
+fn check_grandpa_justification<Block: BlockT>(
+	justifications: &Justifications,
+	hash: Block::Hash,
+	number: NumberFor<Block>,
+	authority_set: &GrandpaAuthoritySet<NumberFor<Block>>,
+) -> Result<bool, String> {
+	let encoded = match justifications.get(GRANDPA_ENGINE_ID) {
+		Some(encoded) => encoded,
+		None => return Ok(false),
+	};
+
+	let voters = finality_grandpa::voter_set::VoterSet::new(authority_set.authorities.iter().cloned())
+		.ok_or_else(|| "GRANDPA authority set is empty".to_string())?;
+
+	sc_finality_grandpa::GrandpaJustification::<Block>::decode_and_verify_finalizes(
+		encoded,
+		(hash, number),
+		authority_set.set_id,
+		&voters,
+	)
+	.map_err(|e| format!("Invalid GRANDPA justification: {:?}", e))?;
+
+	Ok(true)
+}
+
+/// Which consensus engine's pre-digest and seal the [`Verifier`] should check, in addition to the
+/// inherents that are always checked.
+///
+/// This is opt-in (selected when constructing [`import_queue`]) because not every deployment
+/// wants the relay-chain-trusting fast path disabled; a solo chain doing its own block production
+/// may prefer to keep trusting relay chain inclusion for fork choice.
+///
+/// KNOWN GAP: only Aura is implemented. BABE seal verification was requested alongside it, but
+/// its first cut was a no-op stub (always accepting, regardless of the VRF output) and was
+/// removed rather than shipped, since a fake check is worse than none. A real BABE check needs a
+/// VRF output compared against a threshold derived from the author's weight and the epoch
+/// randomness, with the author recovered from the pre-digest's explicit `authority_index` rather
+/// than `slot % authorities.len()` the way Aura's round-robin is. Add a `Babe` variant once that
+/// exists; until then, treat BABE relay chains as unsupported by this verifier, not merely
+/// untested.
+pub enum SealVerification {
+	/// Trust the relay chain for fork choice and authorship; only inherents are checked. This is
+	/// the historical behaviour.
+	Disabled,
+	/// Verify the Aura pre-digest (round-robin authorship) and seal.
+	Aura,
+}
+
+impl SealVerification {
+	fn engine_id(&self) -> Option<[u8; 4]> {
+		match self {
+			SealVerification::Disabled => None,
+			SealVerification::Aura => Some(sp_consensus_aura::AURA_ENGINE_ID),
+		}
+	}
+}
+
+/// Pre-runtime digest contents: the slot the block claims to be authored in.
+struct PreDigest {
+	slot: Slot,
+}
+
+fn decode_pre_digest<Block: BlockT>(
+	header: &Block::Header,
+	engine_id: [u8; 4],
+) -> Result<PreDigest, String> {
+	header
+		.digest()
+		.convert_first(|item| item.try_to::<Vec<u8>>(OpaqueDigestItemId::PreRuntime(&engine_id)))
+		.ok_or_else(|| "Header is missing the pre-runtime digest".to_string())
+		.and_then(|encoded| {
+			let slot = Slot::decode(&mut &encoded[..])
+				.map_err(|e| format!("Failed to decode slot from pre-runtime digest: {:?}", e))?;
+			Ok(PreDigest { slot })
+		})
+}
+
+fn verify_seal_signature<Block: BlockT, AuthorityId>(
+	header: &mut Block::Header,
+	author: &AuthorityId,
+	engine_id: [u8; 4],
+) -> Result<(), String>
+where
+	AuthorityId: RuntimeAppPublic,
+	AuthorityId::Signature: Decode,
+{
+	let seal = header
+		.digest_mut()
+		.pop()
+		.filter(|item| item.as_seal().map_or(false, |(id, _)| id == engine_id))
+		.ok_or_else(|| "Header is unsealed".to_string())?;
+
+	let (_, sig) = seal.as_seal().expect("just matched on `as_seal`; qed");
+	let signature = AuthorityId::Signature::decode(&mut &sig[..])
+		.map_err(|e| format!("Failed to decode seal signature: {:?}", e))?;
+
+	let pre_seal_hash = header.hash();
+	if !author.verify(&pre_seal_hash, &signature) {
+		return Err("Header seal does not match the claimed author".to_string());
+	}
+
+	Ok(())
+}
+
+/// Extracts the relay-parent number a parachain block was built against, by decoding the
+/// `set_validation_data` inherent out of its extrinsics.
+///
+/// This is supplied by the caller, rather than decoded generically here, because only the
+/// concrete runtime knows how to turn its opaque `Block::Extrinsic`s back into calls.
+pub trait RelayParentExtractor<Block: BlockT>: Send + Sync {
+	/// Recover the relay-parent block number the block containing `extrinsics` was built against,
+	/// or `None` if no `set_validation_data` inherent is present.
+	fn extract_relay_parent(&self, extrinsics: &[Block::Extrinsic]) -> Option<u32>;
+}
+
+impl<Block: BlockT, F> RelayParentExtractor<Block> for F
+where
+	F: Fn(&[Block::Extrinsic]) -> Option<u32> + Send + Sync,
+{
+	fn extract_relay_parent(&self, extrinsics: &[Block::Extrinsic]) -> Option<u32> {
+		(self)(extrinsics)
+	}
+}
+
+/// A parachain head that has passed inherent verification, emitted before full block and state
+/// import have completed.
+///
+/// This is sent as soon as inherents check out, so that subscribers don't have to wait for full
+/// import; it is emitted *before* authorship/seal verification runs, so a header seen here can
+/// still go on to be rejected for a bad seal when `seal_verification` is enabled. Subscribers that
+/// need the seal guarantee too should wait for the corresponding import notification instead.
+#[derive(Clone, Debug)]
+pub struct VerifiedHeadNotification<Block: BlockT> {
+	/// The header that passed the inherent check. Not yet authorship/seal-checked; see the type's
+	/// documentation.
+	pub header: Block::Header,
+	/// Whether `check_inherents` reported the block as fully valid. Currently always `true`;
+	/// kept so subscribers don't need to change shape if inherents ever become best-effort.
+	pub inherents_ok: bool,
+	/// The relay-parent number this block was built against, if it could be determined.
+	pub relay_parent: Option<u32>,
+}
+
+/// Tracing key for [`VerifiedHeadStream`]'s underlying notification channel.
+#[derive(Clone)]
+pub struct VerifiedHeadStreamTracingKey;
+
+impl TracingKeyStr for VerifiedHeadStreamTracingKey {
+	const TRACING_KEY: &'static str = "mpsc_verified_head_notification_stream";
+}
+
+/// Stream of [`VerifiedHeadNotification`]s, handed back from [`import_queue`] so that e.g. an RPC
+/// layer can subscribe to parachain heads the moment they're accepted by the verifier, ahead of
+/// Substrate's own post-import notifications.
+pub type VerifiedHeadStream<Block> =
+	NotificationStream<VerifiedHeadNotification<Block>, VerifiedHeadStreamTracingKey>;
+
+/// A verifier that checks the inherents and, optionally, authorship and seals of Aura blocks
+/// against the live authority set reported by `AuraApi` for the block's parent.
+struct Verifier<Client, Block: BlockT, CIDP, AuthorityId, RPE> {
 	client: Arc<Client>,
 	create_inherent_data_providers: CIDP,
+	seal_verification: SealVerification,
+	relay_parent_extractor: Option<RPE>,
+	verified_head_sink: Option<NotificationSender<VerifiedHeadNotification<Block>>>,
+	/// The authority set to fall back to for a block whose parent hasn't had a set persisted for
+	/// it yet (i.e. the genesis block, or the start of this verifier's tracked ancestry). `None`
+	/// when no GRANDPA justification checking was requested.
+	grandpa_genesis_authority_set: Option<GrandpaAuthoritySet<NumberFor<Block>>>,
+	metrics: Option<metrics::Metrics>,
 	_marker: PhantomData<Block>,
 }
 
+impl<Client, Block, CIDP, AuthorityId, RPE> Verifier<Client, Block, CIDP, AuthorityId, RPE>
+where
+	Block: BlockT,
+	Client: AuxStore + sc_client_api::HeaderBackend<Block>,
+	AuthorityId: RuntimeAppPublic + Decode + Encode + Clone + PartialEq,
+	AuthorityId::Signature: Decode,
+	RPE: RelayParentExtractor<Block>,
+{
+	/// Recover the relay parent the block carrying `body` was built against, by decoding its
+	/// `set_validation_data` inherent.
+	fn extract_relay_parent(&self, body: &[Block::Extrinsic]) -> Option<u32> {
+		self.relay_parent_extractor
+			.as_ref()
+			.and_then(|extractor| extractor.extract_relay_parent(body))
+	}
+
+	/// Check that `relay_parent` isn't a regression relative to the parent block's own relay
+	/// parent, and record it in `import_params` so that the child can in turn check against it.
+	fn check_and_record_relay_parent(
+		&self,
+		header: &Block::Header,
+		relay_parent: Option<u32>,
+		import_params: &mut BlockImportParams<Block, ()>,
+	) -> Result<(), String> {
+		let relay_parent = match relay_parent {
+			Some(relay_parent) => relay_parent,
+			None => return Ok(()),
+		};
+
+		let parent_relay_parent =
+			aux_schema::load_relay_parent::<Block>(&*self.client, &header.parent_hash())
+				.map_err(|e| e.to_string())?;
+
+		check_relay_parent_not_regressed(relay_parent, parent_relay_parent)?;
+
+		aux_schema::write_relay_parent::<Block, _>(&header.hash(), relay_parent, |values| {
+			import_params
+				.auxiliary
+				.extend(values.iter().map(|(k, v)| (k.to_vec(), Some(v.to_vec()))));
+		});
+
+		Ok(())
+	}
+
+	/// Whether a block with the given relay parent and number should become the new best head: the
+	/// longest chain of backed blocks consistent with relay-parent ordering, i.e. one that is not
+	/// built on an older relay parent than the current best block, and, among candidates sharing a
+	/// relay parent, the one whose chain is not shorter.
+	fn is_new_best(
+		&self,
+		header: &Block::Header,
+		relay_parent: Option<u32>,
+	) -> Result<bool, String> {
+		let relay_parent = match relay_parent {
+			Some(relay_parent) => relay_parent,
+			// No relay-parent information available (e.g. the runtime doesn't emit the inherent
+			// we know how to decode); fall back to always importing as best, the historical
+			// behaviour.
+			None => return Ok(true),
+		};
+
+		let info = self.client.info();
+		let best_relay_parent = aux_schema::load_relay_parent::<Block>(&*self.client, &info.best_hash)
+			.map_err(|e| e.to_string())?;
+
+		Ok(is_new_best(relay_parent, best_relay_parent, *header.number(), info.best_number))
+	}
+
+	/// Check `justifications` against the GRANDPA authority set tracked for `header`'s own fork
+	/// and, if a valid one is found, mark `import_params` as finalized. Returns an error if a
+	/// GRANDPA justification is present but does not check out.
+	///
+	/// The set is looked up keyed by `header.parent_hash()`, falling back to the genesis set if
+	/// the parent hasn't had one persisted for it yet; this also enacts any previously-announced
+	/// standard change whose `delay` has now elapsed, and queues any new one `header` announces,
+	/// regardless of whether `header` itself carries a justification. The result is persisted
+	/// keyed by `header`'s own hash, so that a sibling built on the same parent never observes a
+	/// change only `header`'s branch announced, and vice versa.
+	fn check_justifications(
+		&self,
+		header: &Block::Header,
+		justifications: &Option<Justifications>,
+		import_params: &mut BlockImportParams<Block, ()>,
+	) -> Result<(), String> {
+		let genesis_authority_set = match &self.grandpa_genesis_authority_set {
+			Some(genesis_authority_set) => genesis_authority_set,
+			None => return Ok(()),
+		};
+
+		let mut authority_set =
+			aux_schema::load_grandpa_authority_set::<Block>(&*self.client, &header.parent_hash())
+				.map_err(|e| e.to_string())?
+				.unwrap_or_else(|| genesis_authority_set.clone());
+
+		if let Some(pending) = &authority_set.pending_change {
+			if *header.number() >= pending.effective_at {
+				let next_authorities = pending.next_authorities.clone();
+				authority_set.set_id += 1;
+				authority_set.authorities = next_authorities;
+				authority_set.pending_change = None;
+			}
+		}
+
+		if let Some(justifications) = justifications {
+			let finalizes = check_grandpa_justification::<Block>(
+				justifications,
+				header.hash(),
+				*header.number(),
+				&authority_set,
+			)?;
+
+			if finalizes {
+				import_params.finalized = true;
+			}
+		}
+
+		if let Some(change) = decode_grandpa_authority_set_change::<Block>(header) {
+			let effective_at = *header.number() + change.delay;
+
+			if effective_at <= *header.number() {
+				authority_set.set_id += 1;
+				authority_set.authorities = change.next_authorities;
+				authority_set.pending_change = None;
+			} else {
+				authority_set.pending_change =
+					Some(GrandpaPendingChange { effective_at, next_authorities: change.next_authorities });
+			}
+		}
+
+		aux_schema::write_grandpa_authority_set::<Block, _>(&header.hash(), &authority_set, |values| {
+			import_params
+				.auxiliary
+				.extend(values.iter().map(|(k, v)| (k.to_vec(), Some(v.to_vec()))));
+		});
+
+		Ok(())
+	}
+}
+
+/// Decode a GRANDPA `ScheduledChange` consensus digest, if `header` carries one.
+///
+/// Forced changes and pause/resume digests are not handled here yet; only the common standard
+/// scheduled-change case is.
+fn decode_grandpa_authority_set_change<Block: BlockT>(
+	header: &Block::Header,
+) -> Option<sp_finality_grandpa::ScheduledChange<NumberFor<Block>>> {
+	header.digest().convert_first(|item| {
+		match item.try_to::<sp_finality_grandpa::ConsensusLog<NumberFor<Block>>>(OpaqueDigestItemId::Consensus(
+			&GRANDPA_ENGINE_ID,
+		)) {
+			Some(sp_finality_grandpa::ConsensusLog::ScheduledChange(change)) => Some(change),
+			_ => None,
+		}
+	})
+}
+
+/// Error out if `relay_parent` is older than `parent_relay_parent`, i.e. a child claims to be
+/// built against a relay-chain state the runtime has already moved past relative to its parent.
+fn check_relay_parent_not_regressed(
+	relay_parent: u32,
+	parent_relay_parent: Option<u32>,
+) -> Result<(), String> {
+	if let Some(parent_relay_parent) = parent_relay_parent {
+		if relay_parent < parent_relay_parent {
+			return Err(format!(
+				"Relay parent {} is older than the parent block's relay parent {}",
+				relay_parent, parent_relay_parent
+			));
+		}
+	}
+
+	Ok(())
+}
+
+/// Whether a candidate with the given `(relay_parent, number)` should displace a best block with
+/// `(best_relay_parent, best_number)`: a strictly newer relay parent always wins; on a tied relay
+/// parent, only a chain that is not shorter does, so that a shallower sibling candidate can't
+/// displace a deeper one built on the same relay parent.
+fn is_new_best<N: PartialOrd>(
+	relay_parent: u32,
+	best_relay_parent: Option<u32>,
+	number: N,
+	best_number: N,
+) -> bool {
+	match best_relay_parent {
+		Some(best_relay_parent) =>
+			relay_parent > best_relay_parent || (relay_parent == best_relay_parent && number >= best_number),
+		None => true,
+	}
+}
+
+impl<Client, Block, CIDP, AuthorityId, RPE> Verifier<Client, Block, CIDP, AuthorityId, RPE>
+where
+	Block: BlockT,
+	Client: ProvideRuntimeApi<Block>,
+	<Client as ProvideRuntimeApi<Block>>::Api: AuraApi<Block, AuthorityId>,
+	AuthorityId: RuntimeAppPublic + Decode + Encode + Clone + PartialEq,
+	AuthorityId::Signature: Decode,
+{
+	/// Check authorship and seal of `header`, consuming the trailing seal digest in the process so
+	/// that the caller can go on to compute `post_hash` over the now-unsealed header.
+	///
+	/// The authority set is read live, via `AuraApi::authorities`, for the block's parent: Aura
+	/// carries no in-band authority-set digest, so this is the only way to observe a rotation
+	/// (e.g. driven by the session pallet) instead of being stuck with whatever was active at
+	/// genesis.
+	fn verify_seal_and_authorship(&self, mut header: Block::Header) -> Result<Block::Header, String> {
+		let engine_id = match self.seal_verification.engine_id() {
+			Some(id) => id,
+			None => return Ok(header),
+		};
+
+		let pre_digest = decode_pre_digest::<Block>(&header, engine_id)?;
+		let parent_hash = *header.parent_hash();
+
+		let authorities = self
+			.client
+			.runtime_api()
+			.authorities(&BlockId::Hash(parent_hash))
+			.map_err(|e| format!("Failed to fetch Aura authorities for {:?}: {:?}", parent_hash, e))?;
+
+		if authorities.is_empty() {
+			return Err("Active Aura authority set is empty".to_string());
+		}
+
+		let author_index = *pre_digest.slot % authorities.len() as u64;
+		let author = &authorities[author_index as usize];
+
+		verify_seal_signature::<Block, AuthorityId>(&mut header, author, engine_id)?;
+
+		Ok(header)
+	}
+}
+
 #[async_trait::async_trait]
-impl<Client, Block, CIDP> VerifierT<Block> for Verifier<Client, Block, CIDP>
+impl<Client, Block, CIDP, AuthorityId, RPE> VerifierT<Block>
+	for Verifier<Client, Block, CIDP, AuthorityId, RPE>
 where
 	Block: BlockT,
-	Client: ProvideRuntimeApi<Block> + Send + Sync,
-	<Client as ProvideRuntimeApi<Block>>::Api: BlockBuilderApi<Block>,
+	Client: ProvideRuntimeApi<Block> + AuxStore + sc_client_api::HeaderBackend<Block> + Send + Sync,
+	<Client as ProvideRuntimeApi<Block>>::Api: BlockBuilderApi<Block> + AuraApi<Block, AuthorityId>,
 	CIDP: CreateInherentDataProviders<Block, ()>,
+	AuthorityId: RuntimeAppPublic + Decode + Encode + Clone + PartialEq + Send + Sync,
+	AuthorityId::Signature: Decode,
+	RPE: RelayParentExtractor<Block> + Send + Sync,
 {
 	async fn verify(
 		&mut self,
 		origin: BlockOrigin,
-		header: Block::Header,
+		mut header: Block::Header,
 		justifications: Option<Justifications>,
 		mut body: Option<Vec<Block::Extrinsic>>,
 	) -> Result<
@@ -59,7 +652,11 @@ where
 		),
 		String,
 	> {
+		let inherents_checked = body.is_some();
+
 		if let Some(inner_body) = body.take() {
+			let check_inherents_start = std::time::Instant::now();
+
 			let inherent_data_providers = self
 				.create_inherent_data_providers
 				.create_inherent_data_providers(*header.parent_hash(), ())
@@ -82,14 +679,28 @@ where
 				)
 				.map_err(|e| format!("{:?}", e))?;
 
+			if let Some(metrics) = &self.metrics {
+				metrics.observe_check_inherents_time(check_inherents_start.elapsed().as_secs_f64());
+			}
+
 			if !inherent_res.ok() {
 				for (i, e) in inherent_res.into_errors() {
 					match inherent_data_providers.try_handle_error(&i, &e).await {
-						Some(r) => r.map_err(|e| format!("{:?}", e))?,
-						None => Err(format!(
-							"Unhandled inherent error from `{}`.",
-							String::from_utf8_lossy(&i)
-						))?,
+						Some(r) => {
+							if let Some(metrics) = &self.metrics {
+								metrics.report_inherent_error(&String::from_utf8_lossy(&i));
+							}
+							r.map_err(|e| format!("{:?}", e))?
+						},
+						None => {
+							if let Some(metrics) = &self.metrics {
+								metrics.report_inherent_error(metrics::UNHANDLED_INHERENT_LABEL);
+							}
+							Err(format!(
+								"Unhandled inherent error from `{}`.",
+								String::from_utf8_lossy(&i)
+							))?
+						},
 					}
 				}
 			}
@@ -98,15 +709,37 @@ where
 			body = Some(inner_body);
 		}
 
+		let relay_parent = self.extract_relay_parent(body.as_deref().unwrap_or(&[]));
+
+		// The inherents are known-good at this point; let subscribers (e.g. RPC) know about the
+		// candidate head as early as possible, rather than waiting for full block and state import
+		// to complete. A block imported without a body had no inherent check, so it is not announced.
+		if let Some(sink) = self.verified_head_sink.as_ref().filter(|_| inherents_checked) {
+			let _ = sink.notify(|| {
+				Ok::<_, ()>(VerifiedHeadNotification { header: header.clone(), inherents_ok: true, relay_parent })
+			});
+		}
+
+		// Warp/fast sync hands us headers it has no way to independently authenticate yet, so
+		// seal verification is skipped for them, same as Substrate's own block importers do.
+		if origin != BlockOrigin::NetworkInitialSync {
+			header = self.verify_seal_and_authorship(header)?;
+		}
+
 		let post_hash = Some(header.hash());
-		let mut block_import_params = BlockImportParams::new(origin, header);
+		let mut block_import_params = BlockImportParams::new(origin, header.clone());
 		block_import_params.body = body;
-		block_import_params.justifications = justifications;
+		block_import_params.justifications = justifications.clone();
+
+		self.check_and_record_relay_parent(&header, relay_parent, &mut block_import_params)?;
+		self.check_justifications(&header, &justifications, &mut block_import_params)?;
 
-		// Best block is determined by the relay chain, or if we are doing the intial sync
-		// we import all blocks as new best.
+		// With async backing a collator may have several candidates in flight that all build on
+		// the same (or a monotonically newer) relay parent before any of them is included, so
+		// fork choice can no longer be a flat "are we initial-syncing" boolean: the new best head
+		// is the tip of the longest chain of backed blocks consistent with relay-parent ordering.
 		block_import_params.fork_choice = Some(ForkChoiceStrategy::Custom(
-			origin == BlockOrigin::NetworkInitialSync,
+			origin == BlockOrigin::NetworkInitialSync || self.is_new_best(&header, relay_parent)?,
 		));
 		block_import_params.post_hash = post_hash;
 
@@ -115,31 +748,216 @@ where
 }
 
 /// Start an import queue for a Cumulus collator that does not uses any special authoring logic.
-pub fn import_queue<Client, Block: BlockT, I, CIDP>(
+///
+/// Pass [`SealVerification::Disabled`] to keep the historical behaviour of only checking
+/// inherents and trusting the relay chain for everything else. Passing [`SealVerification::Aura`]
+/// additionally verifies authorship and the block seal against the *live* Aura authority set,
+/// read via `AuraApi::authorities` for the block's parent on every check. Aura carries no
+/// in-band authority-set digest, so this is the only way to track a rotation (e.g. one driven by
+/// the session pallet); nothing needs to be persisted for it, since the runtime state already is.
+/// BABE seal verification was also requested but is not offered yet; see the gap noted on
+/// [`SealVerification`].
+///
+/// `relay_parent_extractor`, when provided, lets the queue recover the relay-parent number a
+/// block was built against (by decoding its `set_validation_data` inherent) so that, with async
+/// backing, fork choice among several not-yet-included candidates can follow the longest chain of
+/// backed blocks consistent with relay-parent ordering instead of a flat "are we initial-syncing"
+/// boolean.
+///
+/// `grandpa_genesis_authorities`, when provided, turns the queue into a gatekeeper for finality
+/// data: a `Justifications` carrying a GRANDPA commit is decoded and checked against the authority
+/// set tracked for that block's own fork (persisted per-hash in aux storage alongside
+/// authority-set changes, falling back to `grandpa_genesis_authorities` for a block whose parent
+/// hasn't had one persisted yet), and `verify` fails on an invalid proof instead of passing the
+/// justification through untouched.
+///
+/// When `registry` is `Some`, the verifier also registers metrics tracking how long inherent
+/// checks take and, when they fail, which inherent identifier (or "unhandled") produced the
+/// error.
+///
+/// Returns, alongside the queue, a [`VerifiedHeadStream`] that downstream consumers (e.g. RPC
+/// subscriptions) can use to learn about a parachain head as soon as it passes the verifier's
+/// inherent check, ahead of full block and state import completing.
+pub fn import_queue<Client, Block: BlockT, I, CIDP, AuthorityId, RPE>(
 	client: Arc<Client>,
 	block_import: I,
 	create_inherent_data_providers: CIDP,
 	spawner: &impl sp_core::traits::SpawnEssentialNamed,
 	registry: Option<&substrate_prometheus_endpoint::Registry>,
-) -> ClientResult<BasicQueue<Block, I::Transaction>>
+	seal_verification: SealVerification,
+	relay_parent_extractor: Option<RPE>,
+	grandpa_genesis_authorities: Option<GrandpaAuthorityList>,
+) -> ClientResult<(BasicQueue<Block, I::Transaction>, VerifiedHeadStream<Block>)>
 where
 	I: BlockImport<Block, Error = ConsensusError> + Send + Sync + 'static,
 	I::Transaction: Send,
-	Client: ProvideRuntimeApi<Block> + Send + Sync + 'static,
-	<Client as ProvideRuntimeApi<Block>>::Api: BlockBuilderApi<Block>,
+	Client:
+		ProvideRuntimeApi<Block> + AuxStore + sc_client_api::HeaderBackend<Block> + Send + Sync + 'static,
+	<Client as ProvideRuntimeApi<Block>>::Api: BlockBuilderApi<Block> + AuraApi<Block, AuthorityId>,
 	CIDP: CreateInherentDataProviders<Block, ()> + 'static,
+	AuthorityId: RuntimeAppPublic + Decode + Encode + Clone + PartialEq + Send + Sync + 'static,
+	AuthorityId::Signature: Decode,
+	RPE: RelayParentExtractor<Block> + 'static,
 {
+	let grandpa_genesis_authority_set = grandpa_genesis_authorities
+		.map(|authorities| GrandpaAuthoritySet { set_id: 0, authorities, pending_change: None });
+
+	let metrics = registry
+		.map(metrics::Metrics::register)
+		.transpose()
+		.map_err(|e| ClientError::Application(Box::new(e)))?;
+
+	let (verified_head_sink, verified_head_stream) = VerifiedHeadStream::channel();
+
 	let verifier = Verifier {
 		client,
 		create_inherent_data_providers,
+		seal_verification,
+		relay_parent_extractor,
+		verified_head_sink: Some(verified_head_sink),
+		grandpa_genesis_authority_set,
+		metrics,
 		_marker: PhantomData,
 	};
 
-	Ok(BasicQueue::new(
-		verifier,
-		Box::new(block_import),
-		None,
-		spawner,
-		registry,
-	))
+	let queue = BasicQueue::new(verifier, Box::new(block_import), None, spawner, registry);
+
+	Ok((queue, verified_head_stream))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::{generic::Digest, testing::Header};
+
+	type TestBlock = sp_runtime::testing::Block<sp_runtime::testing::ExtrinsicWrapper<u64>>;
+
+	fn header_with_pre_digest(engine_id: [u8; 4], encoded: Vec<u8>) -> Header {
+		Header::new(
+			1,
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Digest { logs: vec![DigestItem::PreRuntime(engine_id, encoded)] },
+		)
+	}
+
+	#[test]
+	fn decode_pre_digest_rejects_missing_digest() {
+		let header = Header::new(1, Default::default(), Default::default(), Default::default(), Default::default());
+
+		let err = decode_pre_digest::<TestBlock>(&header, sp_consensus_aura::AURA_ENGINE_ID).unwrap_err();
+		assert_eq!(err, "Header is missing the pre-runtime digest");
+	}
+
+	#[test]
+	fn decode_pre_digest_decodes_slot() {
+		let slot = Slot::from(42);
+		let header = header_with_pre_digest(sp_consensus_aura::AURA_ENGINE_ID, slot.encode());
+
+		let pre_digest =
+			decode_pre_digest::<TestBlock>(&header, sp_consensus_aura::AURA_ENGINE_ID).unwrap();
+
+		assert_eq!(pre_digest.slot, slot);
+	}
+
+	fn header_with_scheduled_change(
+		next_authorities: GrandpaAuthorityList,
+		delay: u64,
+	) -> Header {
+		let log = sp_finality_grandpa::ConsensusLog::<u64>::ScheduledChange(
+			sp_finality_grandpa::ScheduledChange { next_authorities, delay },
+		);
+		Header::new(
+			1,
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Digest { logs: vec![DigestItem::Consensus(GRANDPA_ENGINE_ID, log.encode())] },
+		)
+	}
+
+	#[test]
+	fn decode_grandpa_authority_set_change_keeps_the_delay() {
+		let header = header_with_scheduled_change(Vec::new(), 7);
+
+		let change = decode_grandpa_authority_set_change::<TestBlock>(&header).unwrap();
+
+		assert_eq!(change.delay, 7);
+	}
+
+	#[test]
+	fn decode_grandpa_authority_set_change_ignores_other_digests() {
+		let header = header_with_pre_digest(sp_consensus_aura::AURA_ENGINE_ID, Slot::from(1).encode());
+
+		assert!(decode_grandpa_authority_set_change::<TestBlock>(&header).is_none());
+	}
+
+	#[test]
+	fn check_grandpa_justification_rejects_invalid_encoding() {
+		let authority = sp_finality_grandpa::AuthorityId::from(sp_core::ed25519::Public::from_raw([1; 32]));
+		let authority_set =
+			GrandpaAuthoritySet { set_id: 0, authorities: vec![(authority, 1)], pending_change: None };
+		let justifications: Justifications = (GRANDPA_ENGINE_ID, vec![0, 1, 2, 3]).into();
+
+		let err = check_grandpa_justification::<TestBlock>(
+			&justifications,
+			Default::default(),
+			1,
+			&authority_set,
+		)
+		.unwrap_err();
+
+		assert!(err.starts_with("Invalid GRANDPA justification"));
+	}
+
+	#[test]
+	fn check_grandpa_justification_is_ok_when_absent() {
+		let authority_set =
+			GrandpaAuthoritySet { set_id: 0, authorities: Vec::new(), pending_change: None };
+		let justifications: Justifications = (*b"OTHR", vec![1, 2, 3]).into();
+
+		let finalizes = check_grandpa_justification::<TestBlock>(
+			&justifications,
+			Default::default(),
+			1,
+			&authority_set,
+		)
+		.unwrap();
+
+		assert!(!finalizes);
+	}
+
+	#[test]
+	fn relay_parent_regression_is_rejected() {
+		assert!(check_relay_parent_not_regressed(5, Some(10)).is_err());
+	}
+
+	#[test]
+	fn relay_parent_holding_steady_or_advancing_is_accepted() {
+		assert!(check_relay_parent_not_regressed(10, Some(10)).is_ok());
+		assert!(check_relay_parent_not_regressed(11, Some(10)).is_ok());
+		assert!(check_relay_parent_not_regressed(0, None).is_ok());
+	}
+
+	#[test]
+	fn newer_relay_parent_is_always_best() {
+		assert!(is_new_best(11, Some(10), 1u32, 100u32));
+	}
+
+	#[test]
+	fn shorter_chain_on_the_same_relay_parent_does_not_displace_the_best() {
+		assert!(!is_new_best(10, Some(10), 5u32, 10u32));
+	}
+
+	#[test]
+	fn longer_or_equal_chain_on_the_same_relay_parent_is_best() {
+		assert!(is_new_best(10, Some(10), 10u32, 10u32));
+		assert!(is_new_best(10, Some(10), 11u32, 10u32));
+	}
+
+	#[test]
+	fn no_prior_best_is_always_best() {
+		assert!(is_new_best(10, None, 1u32, 1u32));
+	}
 }